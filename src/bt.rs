@@ -4,6 +4,7 @@
 
 use std::convert::From;
 use std::fmt;
+use std::ops;
 
 use self::Trit::*;
 
@@ -41,6 +42,7 @@ impl Trit {
 }
 
 
+#[derive(Debug, Clone)]
 pub struct BalancedTernary {
     trits: Vec<Trit>,
 }
@@ -62,6 +64,19 @@ impl BalancedTernary {
     pub fn value(&self) -> i64 {
         self.trits.iter().fold(0, |sum, trit| sum * 3 + trit.value())
     }
+
+    /// The trits with any leading `Zero`s stripped, so that e.g. `0+` and
+    /// `+` compare equal.
+    fn canonical_trits(&self) -> &[Trit] {
+        let first_non_zero = self.trits.iter().position(|&t| t != Zero).unwrap_or(self.trits.len());
+        &self.trits[first_non_zero ..]
+    }
+}
+
+impl PartialEq for BalancedTernary {
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical_trits() == other.canonical_trits()
+    }
 }
 
 impl fmt::Display for BalancedTernary {
@@ -93,10 +108,110 @@ impl From<i64> for BalancedTernary {
     }
 }
 
+impl ops::Neg for BalancedTernary {
+    type Output = Self;
+
+    fn neg(mut self) -> Self {
+        for t in &mut self.trits {
+            *t = match *t {
+                Minus => Plus,
+                Zero  => Zero,
+                Plus  => Minus,
+            };
+        }
+
+        self
+    }
+}
+
+impl ops::Add for BalancedTernary {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self { trits: add_trits(&self.trits, &other.trits) }
+    }
+}
+
+impl ops::Sub for BalancedTernary {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + (-other)
+    }
+}
+
+impl ops::Mul for BalancedTernary {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let mut result = Self::from(0);
+
+        for (shift, &multiplier_trit) in other.trits.iter().rev().enumerate() {
+            if multiplier_trit == Zero {
+                continue;
+            }
+
+            let mut shifted = self.trits.clone();
+            shifted.extend(std::iter::repeat_n(Zero, shift));
+            let addend = Self { trits: shifted };
+
+            result = result + match multiplier_trit {
+                Plus  => addend,
+                Minus => -addend,
+                Zero  => unreachable!(),
+            };
+        }
+
+        result
+    }
+}
+
+/// Adds two trit sequences (most-significant trit first), the way you’d
+/// add two decimal numbers digit-by-digit: starting from the least
+/// significant end, summing each pair of trits plus a carry in `{-1, 0,
+/// +1}`, where a sum in `[-3, 3]` maps to a result trit and an outgoing
+/// carry (e.g. a sum of `+2` is a `Minus` trit with a carry of `+1`, since
+/// `-1 + 3*1 == 2`).
+fn add_trits(a: &[Trit], b: &[Trit]) -> Vec<Trit> {
+    let mut result = Vec::new();
+    let mut carry = 0_i64;
+
+    let mut a_iter = a.iter().rev().copied();
+    let mut b_iter = b.iter().rev().copied();
+
+    loop {
+        let a_trit = a_iter.next();
+        let b_trit = b_iter.next();
+
+        if a_trit.is_none() && b_trit.is_none() && carry == 0 {
+            break;
+        }
+
+        let sum = a_trit.map_or(0, Trit::value) + b_trit.map_or(0, Trit::value) + carry;
+
+        let (trit, next_carry) = match sum {
+            -3 => (Zero,  -1),
+            -2 => (Plus,  -1),
+            -1 => (Minus,  0),
+             0 => (Zero,   0),
+             1 => (Plus,   0),
+             2 => (Minus,  1),
+             3 => (Zero,   1),
+             _ => unreachable!(),
+        };
+
+        result.push(trit);
+        carry = next_carry;
+    }
+
+    result.reverse();
+    result
+}
+
 
 #[cfg(test)]
 mod test {
-    use super::BalancedTernary;
+    use super::*;
 
 	#[test]
 	fn test_many_numbers() {
@@ -104,4 +219,51 @@ mod test {
             assert_eq!(i, BalancedTernary::from(i).value());
 		}
 	}
+
+	/// `From<i64>` only handles non-negative input, so negative values are
+	/// built from a positive one via `Neg` instead — which is exactly what
+	/// these tests are checking agrees with plain `i64` arithmetic.
+	fn bt(i: i64) -> BalancedTernary {
+		if i >= 0 { BalancedTernary::from(i) } else { -BalancedTernary::from(-i) }
+	}
+
+	#[test]
+	fn test_neg() {
+		for i in -100 .. 100 {
+            assert_eq!(-i, (-bt(i)).value());
+		}
+	}
+
+	#[test]
+	fn test_add() {
+		for a in -50 .. 50 {
+			for b in -50 .. 50 {
+                assert_eq!(a + b, (bt(a) + bt(b)).value());
+			}
+		}
+	}
+
+	#[test]
+	fn test_sub() {
+		for a in -50 .. 50 {
+			for b in -50 .. 50 {
+                assert_eq!(a - b, (bt(a) - bt(b)).value());
+			}
+		}
+	}
+
+	#[test]
+	fn test_mul() {
+		for a in -30 .. 30 {
+			for b in -30 .. 30 {
+                assert_eq!(a * b, (bt(a) * bt(b)).value());
+			}
+		}
+	}
+
+	#[test]
+	fn canonicalization_ignores_leading_zeros() {
+		let with_leading_zero = BalancedTernary { trits: vec![Zero, Plus] };
+		assert_eq!(with_leading_zero, BalancedTernary::from(1));
+	}
 }
@@ -76,6 +76,105 @@
 //! assert_eq!("XXVII", input);
 //! ```
 //!
+//! Both also accept the alternate flag (`{:#X}`/`{:#x}`), which uses the
+//! precomposed Unicode Roman numeral glyphs instead of ASCII letters:
+//!
+//! ```
+//! use numerals::roman::{Roman, Numeral::{I, V, X}};
+//!
+//! let input = format!("{:#X}", Roman::from(vec![ X, X, V, I, I ]));
+//! assert_eq!("\u{2169}\u{2169}\u{2164}\u{2160}\u{2160}", input);
+//! ```
+//!
+//! `parse` understands the same glyphs in reverse, decomposing composite
+//! ones like ‘Ⅻ’ (U+216B, twelve) into their constituent numerals:
+//!
+//! ```
+//! use numerals::roman::{Roman, Numeral::{X, I}};
+//!
+//! let input    = Roman::parse("\u{216B}").unwrap();
+//! let expected = Roman::from(vec![ X, I, I ]);
+//! assert_eq!(expected, input);
+//! ```
+//!
+//!
+//! Ergonomic conversions
+//! ---------------------
+//!
+//! For code that wants to use the standard-library conversion traits
+//! instead of calling `parse`/`from`/`{:X}` directly, `Roman` also
+//! implements `FromStr`, `TryFrom<&str>`, `TryFrom<i32>`, and `Display`.
+//! These all return a `RomanError` (which implements `std::error::Error`)
+//! on failure instead of panicking or returning `None`.
+//!
+//! ```
+//! use std::convert::TryFrom;
+//! use numerals::roman::Roman;
+//!
+//! let input: Roman = "XXVII".parse().unwrap();
+//! assert_eq!(input.to_string(), "XXVII");
+//!
+//! assert!(Roman::try_from(0_i32).is_err());
+//! ```
+//!
+//! There’s no `impl TryFrom<i16>`, but the standard library’s blanket
+//! `TryFrom<U> for T where U: Into<T>` means `Roman::try_from(an_i16)` still
+//! compiles, via `Roman: From<i16>`. That blanket impl is infallible and
+//! simply defers to `From::from`, so it **panics** on zero or negative
+//! input exactly like `Roman::from(i16)` does — it is not a panic-safe
+//! alternative. Use `TryFrom<i32>` instead, which is; `from_large` panics
+//! on zero or negative input too.
+//!
+//!
+//! Strict parsing
+//! --------------
+//!
+//! `parse` accepts any string of valid glyphs, even ones that don’t
+//! correspond to a sensible numeral, such as `"IIII"` or `"IC"`. If you’d
+//! rather reject malformed input than silently get a meaningless `value`,
+//! use `parse_canonical`, which only succeeds on input that’s already in
+//! its normalized form.
+//!
+//! ```
+//! use numerals::roman::{Roman, ParseError};
+//!
+//! assert!(Roman::parse_canonical("XXVII").is_ok());
+//! assert_eq!(Roman::parse_canonical("IIII"), Err(ParseError::TooManyRepeats));
+//! ```
+//!
+//!
+//! Vinculum notation for large numbers
+//! ------------------------------------
+//!
+//! There’s no single standard way to write Roman numerals in the tens of
+//! thousands and beyond, but a common historical convention is the
+//! *vinculum*: a bar drawn over a numeral to multiply its value by 1000.
+//! `Roman::from_large` builds numerals this way, splitting its input into
+//! millions, thousands, and units. `Display`, `{:X}`, and `{:x}` all render
+//! the thousands and millions groups with a combining overline (U+0305),
+//! doubled up for the millions group (U+0305 U+033F); `vinculum` is just a
+//! shorthand for `format!("{:X}", ...)`.
+//!
+//! ```
+//! use numerals::roman::Roman;
+//!
+//! let input = Roman::from_large(5000);
+//! assert_eq!(input.vinculum(), "V\u{0305}");
+//! assert_eq!(input.to_string(), "V\u{0305}");
+//! ```
+//!
+//! Because `value` returns a plain `i16` that can’t carry magnitude
+//! information, it refuses (by panicking) to compute a value for any
+//! `Roman` with a vinculum-marked numeral — as `from_large` produces for
+//! inputs of 1000 and above, or `parse` for overlined input — use
+//! `value_large` instead, which returns the full `i32`.
+//!
+//! ```should_panic
+//! use numerals::roman::Roman;
+//!
+//! Roman::from_large(5000).value(); // panics: use value_large() instead
+//! ```
+//!
 //!
 //! Limitations
 //! -----------
@@ -86,10 +185,13 @@
 //! - Similarly, there is no *common* way to handle numbers in the tens of
 //!   thousands, which is why this library uses `i16`-sized integers. Numbers
 //!   in the tens of thousands will work, but will be prefixed with many, many
-//!   `M`s.
+//!   `M`s — unless you use `Roman::from_large` and `vinculum`, which can
+//!   represent the full `i32` range instead.
 
-use std::convert::From;
+use std::convert::{From, TryFrom};
+use std::error::Error;
 use std::fmt;
+use std::str::FromStr;
 
 use self::Numeral::*;
 
@@ -146,25 +248,150 @@ impl Numeral {
         }
     }
 
+    /// The single-codepoint uppercase Unicode Roman numeral glyph for this
+    /// numeral, such as ‘Ⅴ’ (U+2164).
+    fn unicode_upper(self) -> char {
+        match self {
+            I => '\u{2160}',  V => '\u{2164}',  X => '\u{2169}',  L => '\u{216C}',
+            C => '\u{216D}',  D => '\u{216E}',  M => '\u{216F}',
+        }
+    }
+
+    /// The single-codepoint lowercase Unicode Roman numeral glyph for this
+    /// numeral, such as ‘ⅴ’ (U+2174).
+    fn unicode_lower(self) -> char {
+        match self {
+            I => '\u{2170}',  V => '\u{2174}',  X => '\u{2179}',  L => '\u{217C}',
+            C => '\u{217D}',  D => '\u{217E}',  M => '\u{217F}',
+        }
+    }
+
     /// Turn an individual character into its numeral equivalent, if there is
     /// one. Returns `None` otherwise.
     ///
-    /// This accepts either uppercase or lowercase ASCII characters.
+    /// This accepts either uppercase or lowercase ASCII characters, as well
+    /// as the single-value precomposed Unicode Roman numeral glyphs — ‘Ⅰ’,
+    /// ‘Ⅴ’, ‘Ⅹ’, ‘Ⅼ’, ‘Ⅽ’, ‘Ⅾ’, ‘Ⅿ’ (U+2160–U+217F) and their lowercase
+    /// counterparts. Composite glyphs that represent more than one numeral,
+    /// such as ‘Ⅻ’ (twelve), aren’t handled here — see
+    /// `decompose_unicode_char`.
     pub fn from_char(input: char) -> Option<Self> {
         match input {
             'I' | 'i' => Some(I),  'V' | 'v' => Some(V),
             'X' | 'x' => Some(X),  'L' | 'l' => Some(L),
             'C' | 'c' => Some(C),  'D' | 'd' => Some(D),
-            'M' | 'm' => Some(M),      _     => None,
+            'M' | 'm' => Some(M),
+
+            '\u{2160}' | '\u{2170}' => Some(I),
+            '\u{2164}' | '\u{2174}' => Some(V),
+            '\u{2169}' | '\u{2179}' => Some(X),
+            '\u{216C}' | '\u{217C}' => Some(L),
+            '\u{216D}' | '\u{217D}' => Some(C),
+            '\u{216E}' | '\u{217E}' => Some(D),
+            '\u{216F}' | '\u{217F}' => Some(M),
+
+            _ => None,
+        }
+    }
+
+    /// Decomposes one of the composite Unicode Roman numeral glyphs — the
+    /// ones worth more than one `Numeral`, such as ‘Ⅻ’ (U+216B, twelve) —
+    /// into its constituent sequence. Returns `None` for anything else,
+    /// including the single-value glyphs that `from_char` already handles.
+    fn decompose_unicode_char(input: char) -> Option<Vec<Self>> {
+        match input {
+            '\u{2161}' | '\u{2171}' => Some(vec![I, I]),
+            '\u{2162}' | '\u{2172}' => Some(vec![I, I, I]),
+            '\u{2163}' | '\u{2173}' => Some(vec![I, V]),
+            '\u{2165}' | '\u{2175}' => Some(vec![V, I]),
+            '\u{2166}' | '\u{2176}' => Some(vec![V, I, I]),
+            '\u{2167}' | '\u{2177}' => Some(vec![V, I, I, I]),
+            '\u{2168}' | '\u{2178}' => Some(vec![I, X]),
+            '\u{216A}' | '\u{217A}' => Some(vec![X, I]),
+            '\u{216B}' | '\u{217B}' => Some(vec![X, I, I]),
+            _ => None,
+        }
+    }
+}
+
+
+/// How many powers of 1000 a numeral is multiplied by. A numeral written
+/// with a vinculum (overline) is worth 1000 times its usual value; one
+/// written with a *doubled* vinculum is worth 1,000,000 times its usual
+/// value.
+#[derive(PartialEq, Debug, Copy, Clone)]
+enum Magnitude {
+    Ones,
+    Thousands,
+    Millions,
+}
+
+impl Magnitude {
+    fn multiplier(self) -> i32 {
+        match self {
+            Magnitude::Ones       => 1,
+            Magnitude::Thousands  => 1_000,
+            Magnitude::Millions   => 1_000_000,
         }
     }
 }
 
 
+/// An error returned by `Roman::parse_canonical` when the input isn’t a
+/// well-formed, canonical Roman numeral.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum ParseError {
+
+    /// The character at the given byte offset doesn’t map to a numeral.
+    UnknownChar(char, usize),
+
+    /// A repeatable numeral (`I`, `X`, `C`, `M`) appears more than three
+    /// times in a row, or a non-repeatable one (`V`, `L`, `D`) appears more
+    /// than once.
+    TooManyRepeats,
+
+    /// A numeral appears before a larger one without forming one of the six
+    /// legal subtractive pairs (`IV`, `IX`, `XL`, `XC`, `CD`, `CM`).
+    InvalidSubtractive,
+
+    /// The numerals aren’t arranged in non-increasing order of value.
+    NotDescending,
+
+    /// The numerals parsed to a value that doesn’t fit in an `i16`, or to
+    /// zero (such as an empty string).
+    ValueOutOfRange,
+}
+
+
+/// An error returned when converting into a `Roman` value fails.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum RomanError {
+
+    /// The input number was zero or negative. The Romans had the *concept*
+    /// of zero, but no numeral for it, and no notion of a negative numeral.
+    NotPositive,
+
+    /// The input string contained a character that doesn’t map to a
+    /// numeral.
+    InvalidChar(char),
+}
+
+impl fmt::Display for RomanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RomanError::NotPositive    => write!(f, "number is zero or negative"),
+            RomanError::InvalidChar(c) => write!(f, "{:?} is not a Roman numeral character", c),
+        }
+    }
+}
+
+impl Error for RomanError {}
+
+
 /// A sequence of Roman numerals.
 #[derive(PartialEq, Debug)]
 pub struct Roman {
-    numerals: Vec<Numeral>,
+    numerals: Vec<(Numeral, Magnitude)>,
 }
 
 impl Roman {
@@ -173,28 +400,110 @@ impl Roman {
     /// `None` if there’s a character in the input string that doesn’t map to
     /// a numeral.
     ///
-    /// This accepts either uppercase or lowercase ASCII characters.
+    /// This accepts either uppercase or lowercase ASCII characters, as well
+    /// as the precomposed Unicode Roman numeral glyphs (U+2160–U+217F) —
+    /// single-value ones like ‘Ⅴ’ are read as one `Numeral`, and composite
+    /// ones like ‘Ⅻ’ are decomposed into their constituent sequence (`X`,
+    /// `I`, `I`). A numeral may be followed by a combining overline
+    /// (U+0305), or by both a combining overline and a combining double
+    /// overline (U+033F), to parse it as a vinculum numeral worth 1000
+    /// or 1,000,000 times its usual value respectively — see `from_large`
+    /// and `vinculum`.
     pub fn parse(input: &str) -> Option<Self> {
         let mut numerals = Vec::new();
+        let mut chars = input.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if let Some(composite) = Numeral::decompose_unicode_char(c) {
+                numerals.extend(composite.into_iter().map(|n| (n, Magnitude::Ones)));
+                continue;
+            }
 
-        for c in input.chars() {
-            numerals.push(Numeral::from_char(c)?);
+            let numeral = Numeral::from_char(c)?;
+
+            let mut overline = false;
+            let mut double_overline = false;
+
+            while let Some(&next) = chars.peek() {
+                match next {
+                    '\u{0305}' => { overline = true; chars.next(); },
+                    '\u{033F}' => { double_overline = true; chars.next(); },
+                    _ => break,
+                }
+            }
+
+            let magnitude = match (overline, double_overline) {
+                (true, true)  => Magnitude::Millions,
+                (true, false) => Magnitude::Thousands,
+                (false, _)    => Magnitude::Ones,
+            };
+
+            numerals.push((numeral, magnitude));
         }
 
         Some(Self { numerals })
     }
 
-    /// Converts this string of numerals into a `i32` actual number.
+    /// Parses a string of characters the same way as `parse`, but also
+    /// rejects anything that isn’t already in canonical, normalized form —
+    /// so `"IIII"`, `"VV"`, `"IC"`, and `"IM"` are all refused, rather than
+    /// silently parsing into a meaningless `value`.
+    ///
+    /// This accepts either uppercase or lowercase ASCII characters, and
+    /// checks them against the usual rules: `I`, `X`, `C`, and `M` may each
+    /// repeat up to three times in a row; `V`, `L`, and `D` may not repeat
+    /// at all; and the only numerals allowed to precede a larger one are
+    /// the six legal subtractive pairs (`IV`, `IX`, `XL`, `XC`, `CD`, `CM`).
+    ///
+    /// This never panics: input that overflows an `i16`, or that parses to
+    /// zero (such as an empty string), is rejected with
+    /// `ParseError::ValueOutOfRange` instead.
+    pub fn parse_canonical(input: &str) -> Result<Self, ParseError> {
+        let uppercased = input.to_uppercase();
+
+        let roman = match Self::parse(&uppercased) {
+            Some(roman) => roman,
+            None        => return Err(classify_canonical_error(&uppercased)),
+        };
+
+        let value = match roman.value_checked() {
+            Some(value) if value > 0 => value,
+            _                        => return Err(ParseError::ValueOutOfRange),
+        };
+
+        // `numerals_for`/`From<i16>` render any value above 3999 with a
+        // leading run of four or more `M`s, so input such as "MMMM" would
+        // round-trip successfully — and `classify_canonical_error` only
+        // ever gets a chance to reject it when the round-trip *fails*.
+        // Reject it here unconditionally instead.
+        if value > 3999 {
+            return Err(ParseError::TooManyRepeats);
+        }
+
+        if format!("{:X}", Self::from(value)) == uppercased {
+            Ok(roman)
+        } else {
+            Err(classify_canonical_error(&uppercased))
+        }
+    }
+
+    /// Converts this string of numerals into a `i16` actual number.
     ///
     /// # Panics
     ///
     /// - This function panics when passed in a negative number or zero, as
     ///   the Romans didn’t have a way to write those down!
+    /// - This function panics if any numeral is marked with a vinculum (see
+    ///   `from_large`), since `i16` can’t represent the resulting value —
+    ///   use `value_large` for those instead.
     pub fn value(&self) -> i16 {
+        assert!(self.numerals.iter().all(|&(_, magnitude)| magnitude == Magnitude::Ones),
+                "value() can't represent a vinculum-marked numeral; use value_large() instead");
+
         let mut total = 0;
         let mut max = 0;
 
-        for n in self.numerals.iter().map(|n| n.value()).rev() {
+        for n in self.numerals.iter().map(|&(n, _)| n.value()).rev() {
             total += if n >= max { n } else { -n };
 
             if max < n {
@@ -211,7 +520,7 @@ impl Roman {
         let mut total: i16 = 0;
         let mut max = 0;
 
-        for n in self.numerals.iter().map(|n| n.value()).rev() {
+        for n in self.numerals.iter().map(|&(n, _)| n.value()).rev() {
             let amount_to_add = if n >= max { n } else { -n };
             total = total.checked_add(amount_to_add)?;
 
@@ -222,12 +531,91 @@ impl Roman {
 
         Some(total)
     }
+
+    /// Converts this string of numerals into its `i32` value, treating any
+    /// vinculum-marked numeral (see `from_large`) as being worth 1000 or
+    /// 1,000,000 times its usual value. Unlike `value`, this is able to
+    /// represent the full `i32` range.
+    pub fn value_large(&self) -> i32 {
+        let mut total = 0;
+        let mut max = 0;
+
+        for &(n, magnitude) in self.numerals.iter().rev() {
+            let value = i32::from(n.value()) * magnitude.multiplier();
+            total += if value >= max { value } else { -value };
+
+            if max < value {
+                max = value;
+            }
+        }
+
+        total
+    }
+
+    /// Formats this `Roman` value using vinculum (overline) notation: a
+    /// numeral worth 1000 times its usual value is followed by a combining
+    /// overline (U+0305), and one worth 1,000,000 times its usual value is
+    /// followed by that overline plus a combining double overline
+    /// (U+033F).
+    ///
+    /// This is the same rendering `{:X}` and `Display` already produce —
+    /// they emit the vinculum marks themselves — so `vinculum` is just a
+    /// convenient, import-free way to ask for it.
+    pub fn vinculum(&self) -> String {
+        format!("{:X}", self)
+    }
+
+    /// Converts a number into a `Roman` value, using vinculum (overline)
+    /// notation for its thousands and millions components so that the full
+    /// `i32` range can be represented without a long run of `M`s.
+    ///
+    /// The input is split into `millions = n / 1_000_000`,
+    /// `thousands = (n / 1000) % 1000`, and `units = n % 1000`; each group is
+    /// encoded with the usual subtractive logic, and the thousands and
+    /// millions groups are marked so that `vinculum` renders them overlined.
+    ///
+    /// # Panics
+    ///
+    /// - This function panics when passed in a negative number or zero, as
+    ///   the Romans didn’t have a way to write those down!
+    pub fn from_large(number: i32) -> Self {
+        assert!(number > 0);
+
+        let millions  = (number / 1_000_000) as i16;
+        let thousands = ((number / 1_000) % 1_000) as i16;
+        let units     = (number % 1_000) as i16;
+
+        let mut numerals = Vec::new();
+
+        for (group, magnitude) in &[ (millions, Magnitude::Millions),
+                                      (thousands, Magnitude::Thousands),
+                                      (units, Magnitude::Ones) ] {
+
+            for n in numerals_for(*group) {
+                numerals.push((n, *magnitude));
+            }
+        }
+
+        Self { numerals }
+    }
 }
 
 impl fmt::LowerHex for Roman {
+    /// Formats using lowercase ASCII letters, or — with the alternate flag
+    /// (`{:#x}`) — the single-codepoint lowercase Unicode Roman numeral
+    /// glyphs, falling back to ASCII for anything without one. A numeral
+    /// marked with a vinculum (see `from_large`) is followed by the same
+    /// combining overline(s) that `vinculum` uses, so the formatted output
+    /// always round-trips back through `parse`.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for n in &self.numerals {
-            write!(f, "{}", n.ascii_lower())?
+        for &(n, magnitude) in &self.numerals {
+            if f.alternate() {
+                write!(f, "{}", n.unicode_lower())?
+            } else {
+                write!(f, "{}", n.ascii_lower())?
+            }
+
+            write_vinculum_marks(f, magnitude)?;
         }
 
         Ok(())
@@ -235,50 +623,184 @@ impl fmt::LowerHex for Roman {
 }
 
 impl fmt::UpperHex for Roman {
+    /// Formats using uppercase ASCII letters, or — with the alternate flag
+    /// (`{:#X}`) — the single-codepoint uppercase Unicode Roman numeral
+    /// glyphs, falling back to ASCII for anything without one. A numeral
+    /// marked with a vinculum (see `from_large`) is followed by the same
+    /// combining overline(s) that `vinculum` uses, so the formatted output
+    /// always round-trips back through `parse`.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for n in &self.numerals {
-            write!(f, "{}", n.ascii_upper())?;
+        for &(n, magnitude) in &self.numerals {
+            if f.alternate() {
+                write!(f, "{}", n.unicode_upper())?;
+            } else {
+                write!(f, "{}", n.ascii_upper())?;
+            }
+
+            write_vinculum_marks(f, magnitude)?;
         }
 
         Ok(())
     }
 }
 
+/// Writes the combining overline mark(s) for a numeral's vinculum
+/// magnitude — nothing for `Magnitude::Ones`, one overline (U+0305) for
+/// `Thousands`, and that plus a combining double overline (U+033F)
+/// for `Millions`. Shared by `UpperHex`, `LowerHex`, and `vinculum`, so
+/// every formatting mode stays faithful to a numeral's magnitude.
+fn write_vinculum_marks(f: &mut fmt::Formatter, magnitude: Magnitude) -> fmt::Result {
+    match magnitude {
+        Magnitude::Ones      => Ok(()),
+        Magnitude::Thousands => write!(f, "\u{0305}"),
+        Magnitude::Millions  => write!(f, "\u{0305}\u{033F}"),
+    }
+}
+
 impl From<Vec<Numeral>> for Roman {
     fn from(numerals: Vec<Numeral>) -> Self {
-        Self { numerals }
+        Self { numerals: numerals.into_iter().map(|n| (n, Magnitude::Ones)).collect() }
     }
 }
 
 impl From<i16> for Roman {
-    fn from(mut number: i16) -> Self {
+    fn from(number: i16) -> Self {
         assert!(number > 0);
-        let mut numerals = Vec::new();
+        let numerals = numerals_for(number).into_iter().map(|n| (n, Magnitude::Ones)).collect();
+        Self { numerals }
+    }
+}
 
-        for &(secondary, primary) in &[ (C, M), (C, D),
-                                        (X, C), (X, L),
-                                        (I, X), (I, V) ] {
+impl fmt::Display for Roman {
+    /// Formats using uppercase ASCII letters, the same as `{:X}`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::UpperHex::fmt(self, f)
+    }
+}
 
-            while number >= primary.value() {
-                number -= primary.value();
-                numerals.push(primary);
-            }
+impl FromStr for Roman {
+    type Err = RomanError;
 
-            let difference = primary.value() - secondary.value();
-            if number >= difference {
-                number -= difference;
-                numerals.push(secondary);
-                numerals.push(primary);
-            }
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse(input).ok_or_else(|| {
+            let bad_char = input.chars()
+                .find(|&c| Numeral::from_char(c).is_none() && Numeral::decompose_unicode_char(c).is_none())
+                .unwrap_or(' ');
+
+            RomanError::InvalidChar(bad_char)
+        })
+    }
+}
+
+impl TryFrom<&str> for Roman {
+    type Error = RomanError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        input.parse()
+    }
+}
+
+// There’s no `impl TryFrom<i16> for Roman`: the standard library already
+// provides a blanket `TryFrom<U> for T where U: Into<T>` for any `T: From<U>`,
+// and `Roman: From<i16>` already exists above, so adding our own would
+// conflict with it. That blanket impl is infallible (`Error = Infallible`)
+// and simply defers to `From::from`, panicking the same way — it doesn’t
+// check for zero or negative input, which is exactly what `TryFrom<i32>`
+// below does for the `from_large` path instead.
+
+impl TryFrom<i32> for Roman {
+    type Error = RomanError;
+
+    /// Converts an `i32` into a `Roman` value using vinculum notation (see
+    /// `from_large`), returning `RomanError::NotPositive` instead of
+    /// panicking on zero or negative input.
+    fn try_from(number: i32) -> Result<Self, Self::Error> {
+        if number > 0 {
+            Ok(Self::from_large(number))
         }
+        else {
+            Err(RomanError::NotPositive)
+        }
+    }
+}
+
+/// Computes the subtractive-notation sequence of numerals for a value,
+/// without any vinculum marking. Values above 3999 get a leading run of
+/// four or more `M`s.
+fn numerals_for(mut number: i16) -> Vec<Numeral> {
+    let mut numerals = Vec::new();
 
-        while number > 0 {
-            number -= 1;
-            numerals.push(I);
+    for &(secondary, primary) in &[ (C, M), (C, D),
+                                    (X, C), (X, L),
+                                    (I, X), (I, V) ] {
+
+        while number >= primary.value() {
+            number -= primary.value();
+            numerals.push(primary);
         }
 
-        Self { numerals }
+        let difference = primary.value() - secondary.value();
+        if number >= difference {
+            number -= difference;
+            numerals.push(secondary);
+            numerals.push(primary);
+        }
+    }
+
+    while number > 0 {
+        number -= 1;
+        numerals.push(I);
+    }
+
+    numerals
+}
+
+/// Scans an already-uppercased string to work out *why* `parse_canonical`
+/// should reject it. Called only once `parse` or the round-trip check has
+/// already found a problem, so it doesn’t need to handle the well-formed case.
+fn classify_canonical_error(uppercased: &str) -> ParseError {
+    const SUBTRACTIVE_PAIRS: &[(Numeral, Numeral)] = &[ (I, V), (I, X),
+                                                         (X, L), (X, C),
+                                                         (C, D), (C, M) ];
+
+    let mut numerals = Vec::new();
+    for (offset, c) in uppercased.char_indices() {
+        match Numeral::from_char(c) {
+            Some(n) => numerals.push(n),
+            None    => return ParseError::UnknownChar(c, offset),
+        }
+    }
+
+    let mut i = 0;
+    while i < numerals.len() {
+        let n = numerals[i];
+        let repeatable = matches!(n, I | X | C | M);
+
+        let mut run = 1;
+        while i + run < numerals.len() && numerals[i + run] == n {
+            run += 1;
+        }
+
+        let occurrences = numerals.iter().filter(|&&m| m == n).count();
+        if (repeatable && run > 3) || (!repeatable && occurrences > 1) {
+            return ParseError::TooManyRepeats;
+        }
+
+        i += run;
+    }
+
+    for i in 0 .. numerals.len().saturating_sub(1) {
+        if numerals[i].value() < numerals[i + 1].value() {
+            let legal_pair = SUBTRACTIVE_PAIRS.contains(&(numerals[i], numerals[i + 1]));
+            let single_smaller = i == 0 || numerals[i - 1].value() >= numerals[i + 1].value();
+
+            if !legal_pair || !single_smaller {
+                return ParseError::InvalidSubtractive;
+            }
+        }
     }
+
+    ParseError::NotDescending
 }
 
 
@@ -323,4 +845,123 @@ mod test {
             .unwrap()
             .value();
     }
+
+    #[test]
+    #[should_panic]
+    fn value_panic_on_vinculum_marked() {
+        Roman::from_large(5_000).value();
+    }
+
+    #[test]
+    fn test_vinculum_formatting() {
+        assert_eq!(Roman::from_large(5_000).vinculum(), "V\u{0305}");
+        assert_eq!(Roman::from_large(4_000).vinculum(), "I\u{0305}V\u{0305}");
+        assert_eq!(Roman::from_large(1_000_000).vinculum(), "I\u{0305}\u{033F}");
+        assert_eq!(
+            Roman::from_large(1_234_567).vinculum(),
+            "I\u{0305}\u{033F}C\u{0305}C\u{0305}X\u{0305}X\u{0305}X\u{0305}I\u{0305}V\u{0305}DLXVII"
+        );
+    }
+
+    #[test]
+    fn test_vinculum_round_trip() {
+        for i in [1, 27, 999, 1_000, 5_000, 9_999, 1_000_000, 2_147_483_647] {
+            let roman = Roman::from_large(i);
+            assert_eq!(i, roman.value_large());
+            assert_eq!(roman, Roman::parse(&roman.vinculum()).unwrap());
+        }
+    }
+
+    #[test]
+    fn parse_canonical_accepts_well_formed_numerals() {
+        assert_eq!(Roman::parse_canonical("XXVII"), Ok(Roman::from(27)));
+        assert_eq!(Roman::parse_canonical("mcmxciv"), Ok(Roman::from(1994)));
+    }
+
+    #[test]
+    fn parse_canonical_rejects_too_many_repeats() {
+        assert_eq!(Roman::parse_canonical("IIII"), Err(ParseError::TooManyRepeats));
+        assert_eq!(Roman::parse_canonical("VV"), Err(ParseError::TooManyRepeats));
+    }
+
+    #[test]
+    fn parse_canonical_rejects_four_thousands() {
+        assert_eq!(Roman::parse_canonical("MMMM"), Err(ParseError::TooManyRepeats));
+        assert_eq!(Roman::parse_canonical("MMMMM"), Err(ParseError::TooManyRepeats));
+        assert_eq!(Roman::parse_canonical("MMMMCMXCIX"), Err(ParseError::TooManyRepeats));
+        assert!(Roman::parse_canonical("MMMCMXCIX").is_ok());
+    }
+
+    #[test]
+    fn parse_canonical_rejects_invalid_subtractives() {
+        assert_eq!(Roman::parse_canonical("IC"), Err(ParseError::InvalidSubtractive));
+        assert_eq!(Roman::parse_canonical("IM"), Err(ParseError::InvalidSubtractive));
+    }
+
+    #[test]
+    fn parse_canonical_rejects_unknown_chars() {
+        assert_eq!(Roman::parse_canonical("XY"), Err(ParseError::UnknownChar('Y', 1)));
+    }
+
+    #[test]
+    fn parse_canonical_rejects_empty_input_without_panicking() {
+        assert_eq!(Roman::parse_canonical(""), Err(ParseError::ValueOutOfRange));
+    }
+
+    #[test]
+    fn parse_canonical_rejects_overflow_without_panicking() {
+        let all_ms: String = std::iter::repeat('M').take(40).collect();
+        assert_eq!(Roman::parse_canonical(&all_ms), Err(ParseError::ValueOutOfRange));
+    }
+
+    #[test]
+    fn parse_single_value_unicode_glyphs() {
+        let input    = Roman::parse("\u{2169}\u{2164}\u{2160}\u{2160}").unwrap();
+        let expected = Roman::from(vec![ X, V, I, I ]);
+        assert_eq!(expected, input);
+    }
+
+    #[test]
+    fn parse_composite_unicode_glyphs() {
+        let input    = Roman::parse("\u{216B}").unwrap();
+        let expected = Roman::from(vec![ X, I, I ]);
+        assert_eq!(expected, input);
+
+        let input    = Roman::parse("\u{2163}").unwrap();
+        let expected = Roman::from(vec![ I, V ]);
+        assert_eq!(expected, input);
+    }
+
+    #[test]
+    fn format_unicode_glyphs() {
+        assert_eq!(format!("{:#X}", Roman::from(27)), "\u{2169}\u{2169}\u{2164}\u{2160}\u{2160}");
+        assert_eq!(format!("{:#x}", Roman::from(27)), "\u{2179}\u{2179}\u{2174}\u{2170}\u{2170}");
+    }
+
+    #[test]
+    fn display_defaults_to_uppercase() {
+        assert_eq!(Roman::from(27).to_string(), "XXVII");
+    }
+
+    #[test]
+    fn from_str_parses_valid_input() {
+        let input: Roman = "xxvii".parse().unwrap();
+        assert_eq!(input, Roman::from(27));
+    }
+
+    #[test]
+    fn from_str_reports_invalid_char() {
+        assert_eq!("XY".parse::<Roman>(), Err(RomanError::InvalidChar('Y')));
+    }
+
+    #[test]
+    fn try_from_str_matches_from_str() {
+        assert_eq!(Roman::try_from("XXVII"), Ok(Roman::from(27)));
+    }
+
+    #[test]
+    fn try_from_i32_rejects_non_positive() {
+        assert_eq!(Roman::try_from(-1_i32), Err(RomanError::NotPositive));
+        assert_eq!(Roman::try_from(5_000_i32), Ok(Roman::from_large(5_000)));
+    }
 }